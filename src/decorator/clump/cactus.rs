@@ -1,7 +1,7 @@
 use vocs::position::{QuadPosition, Offset, dir};
 use vocs::view::QuadMut;
 use vocs::indexed::Target;
-use matcher::BlockMatcher;
+use matcher::{BlockMatcher, Matcher};
 use decorator::{Decorator, Result};
 use java_rand::Random;
 
@@ -39,7 +39,7 @@ impl<B> Decorator<B> for CactusDecorator<B> where B: Target {
 pub struct CactusBlocks<B> where B: Target {
 	pub replace: BlockMatcher<B>, // Air
 	pub base: BlockMatcher<B>, // Cactus / Sand
-	pub solid: BlockMatcher<B>, // any solid block
+	pub solid: Matcher<B>, // any block whose material is solid
 	pub block: B // Cactus
 }
 
@@ -97,9 +97,4 @@ impl Default for CactusSettings {
 			add_height: 2
 		}
 	}
-}
-
-// Clump settings:
-// iterations = 10
-// horizontal_variation = 8
-// vertical_variation = 4
\ No newline at end of file
+}
\ No newline at end of file
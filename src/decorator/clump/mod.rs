@@ -0,0 +1,65 @@
+pub mod cactus;
+pub mod plant;
+
+use vocs::indexed::Target;
+use vocs::view::QuadMut;
+use vocs::position::{QuadPosition, Offset};
+use decorator::{Decorator, Result};
+use java_rand::Random;
+
+/// Wraps a `Decorator`, scattering several placement attempts around the origin position
+/// to reproduce vanilla patch generation (cactus, flowers, and similar plants growing in
+/// loose clumps rather than single blocks).
+pub struct ClumpDecorator<B, D> where B: Target, D: Decorator<B> {
+	pub decorator: D,
+	pub settings: ClumpSettings
+}
+
+impl<B, D> Decorator<B> for ClumpDecorator<B, D> where B: Target, D: Decorator<B> {
+	fn generate(&self, quad: &mut QuadMut<B>, rng: &mut Random, position: QuadPosition) -> Result {
+		for _ in 0..self.settings.iterations {
+			let dx = Self::triangular(rng, self.settings.horizontal_variation);
+			let dy = Self::triangular(rng, self.settings.vertical_variation);
+			let dz = Self::triangular(rng, self.settings.horizontal_variation);
+
+			if let Some(offset) = position.offset(dx, dy, dz) {
+				self.decorator.generate(quad, rng, offset)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl<B, D> ClumpDecorator<B, D> where B: Target, D: Decorator<B> {
+	/// A symmetric triangular offset in `-(variation - 1)..=(variation - 1)`, biased toward zero.
+	/// `variation == 0` disables the axis instead of reaching `next_u32_bound(0)`, which panics.
+	fn triangular(rng: &mut Random, variation: u32) -> i32 {
+		if variation == 0 {
+			return 0;
+		}
+
+		rng.next_u32_bound(variation) as i32 - rng.next_u32_bound(variation) as i32
+	}
+}
+
+pub struct ClumpSettings {
+	/// Number of placement attempts scattered around the origin.
+	pub iterations: u32,
+	/// Horizontal spread of a single attempt: offsets reach at most `horizontal_variation - 1`
+	/// in either direction.
+	pub horizontal_variation: u32,
+	/// Vertical spread of a single attempt: offsets reach at most `vertical_variation - 1`
+	/// in either direction.
+	pub vertical_variation: u32
+}
+
+impl Default for ClumpSettings {
+	fn default() -> Self {
+		ClumpSettings {
+			iterations: 10,
+			horizontal_variation: 8,
+			vertical_variation: 4
+		}
+	}
+}
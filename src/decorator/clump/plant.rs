@@ -1,31 +1,32 @@
 use rng::JavaRng;
 use vocs::indexed::Target;
 use vocs::view::QuadMut;
-use vocs::position::QuadPosition;
+use vocs::position::{QuadPosition, Offset, dir};
 use decorator::{Decorator, Result};
-use matcher::BlockMatcher;
+use matcher::Matcher;
+use query::QuadQuery;
 
 // Pumpkin: On grass, replacing air or {material:ground_cover}
 
-pub struct PlantDecorator<B, M, R> where B: Target, M: BlockMatcher<B>, R: BlockMatcher<B> {
+pub struct PlantDecorator<B> where B: Target {
 	pub block: B,
-	pub base: M,
-	pub replace: R
+	pub base: Matcher<B>,
+	pub replace: Matcher<B>
 }
 
-impl<B, M, R> Decorator<B> for PlantDecorator<B, M, R> where B: Target, M: BlockMatcher<B>, R: BlockMatcher<B> {
+impl<B> Decorator<B> for PlantDecorator<B> where B: Target {
 	fn generate(&self, quad: &mut QuadMut<B>, _: &mut JavaRng, position: QuadPosition) -> Result {
-		// TODO: Check if the block is above the heightmap (how?)
-
 		if !self.replace.matches(quad.get(position)) {
 			return Ok(());
 		}
 
-		match position.offset(0, -1, 0) {
-			Some(below) => if !self.base.matches(quad.get(below)) {
-				return Ok(())
-			},
-			None => return Ok(())
+		// Only place on the real surface: the position must sit directly above the
+		// topmost block in its column that matches `base`, not merely above some `base`
+		// block buried underground.
+		let top = quad.column_top(position.x(), position.z(), &self.base);
+
+		if top.and_then(|top| top.offset(dir::Up)) != Some(position) {
+			return Ok(());
 		}
 
 		quad.set_immediate(position, &self.block);
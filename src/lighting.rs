@@ -0,0 +1,162 @@
+//! Flood-fill sky/block light propagation over a quad.
+
+use std::collections::{HashMap, VecDeque};
+use vocs::indexed::Target;
+use vocs::position::{QuadPosition, Offset, dir};
+use vocs::view::QuadMut;
+
+const QUAD_VOLUME: usize = 16 * 16 * 256;
+
+/// Which of the two light channels an update belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightKind {
+	Block,
+	Sky
+}
+
+/// A single node in the propagation queue.
+#[derive(Debug, Clone, Copy)]
+pub struct LightUpdate {
+	pub kind: LightKind,
+	pub position: QuadPosition
+}
+
+/// Packed, nibble-resolution (0-15) light storage for a quad.
+pub struct NibbleArray {
+	data: Box<[u8]>
+}
+
+impl NibbleArray {
+	pub fn new() -> Self {
+		NibbleArray { data: vec![0; QUAD_VOLUME / 2].into_boxed_slice() }
+	}
+
+	pub fn get(&self, position: QuadPosition) -> u8 {
+		let index = position.yzx();
+		let byte = self.data[index / 2];
+
+		if index & 1 == 0 { byte & 0x0F } else { (byte >> 4) & 0x0F }
+	}
+
+	pub fn set(&mut self, position: QuadPosition, value: u8) {
+		let index = position.yzx();
+		let shift = if index & 1 == 0 { 0 } else { 4 };
+		let mask = !(0x0F << shift);
+
+		self.data[index / 2] = (self.data[index / 2] & mask) | ((value & 0x0F) << shift);
+	}
+}
+
+/// Sky and block light, stored alongside a quad.
+pub struct LightData {
+	pub block: NibbleArray,
+	pub sky: NibbleArray
+}
+
+impl LightData {
+	pub fn new() -> Self {
+		LightData { block: NibbleArray::new(), sky: NibbleArray::new() }
+	}
+
+	fn get(&self, kind: LightKind, position: QuadPosition) -> u8 {
+		match kind {
+			LightKind::Block => self.block.get(position),
+			LightKind::Sky => self.sky.get(position)
+		}
+	}
+
+	fn set(&mut self, kind: LightKind, position: QuadPosition, value: u8) {
+		match kind {
+			LightKind::Block => self.block.set(position, value),
+			LightKind::Sky => self.sky.set(position, value)
+		}
+	}
+}
+
+/// Per-block light levels, used for both emission (how much light a block gives off)
+/// and opacity (how much light a block blocks).
+#[derive(Debug, Clone)]
+pub struct LightLevels<B> where B: Target {
+	levels: HashMap<B, u8>
+}
+
+impl<B> LightLevels<B> where B: Target {
+	pub fn new() -> Self {
+		LightLevels { levels: HashMap::new() }
+	}
+
+	pub fn set(&mut self, block: B, level: u8) {
+		self.levels.insert(block, level);
+	}
+
+	pub fn level(&self, block: &B) -> u8 {
+		self.levels.get(block).cloned().unwrap_or(0)
+	}
+}
+
+/// Computes sky and block light for a quad using a flood-fill over a queue of updates.
+pub struct LightSolver<B> where B: Target {
+	pub emission: LightLevels<B>,
+	pub opacity: LightLevels<B>
+}
+
+impl<B> LightSolver<B> where B: Target {
+	/// Seeds the queue with block light sources and the top of every column for sky light.
+	pub fn seed(&self, quad: &QuadMut<B>, light: &mut LightData, queue: &mut VecDeque<LightUpdate>) {
+		for x in 0..16 {
+			for z in 0..16 {
+				for y in 0..256 {
+					let position = QuadPosition::new(x, y as u16, z);
+					let emission = self.emission.level(quad.get(position));
+
+					if emission > 0 {
+						light.set(LightKind::Block, position, emission);
+						queue.push_back(LightUpdate { kind: LightKind::Block, position });
+					}
+				}
+
+				let top = QuadPosition::new(x, 255, z);
+
+				light.set(LightKind::Sky, top, 15);
+				queue.push_back(LightUpdate { kind: LightKind::Sky, position: top });
+			}
+		}
+	}
+
+	/// Drains the queue, propagating light outward one node at a time.
+	pub fn propagate(&self, quad: &QuadMut<B>, light: &mut LightData, queue: &mut VecDeque<LightUpdate>) {
+		while let Some(update) = queue.pop_front() {
+			let current = light.get(update.kind, update.position);
+
+			if let Some(down) = update.position.offset(dir::Down) {
+				let opacity = self.opacity.level(quad.get(down));
+
+				// Sky light propagating straight down into clear air does not decrease,
+				// so daylight reaches the surface at full strength.
+				let expected = if update.kind == LightKind::Sky && opacity == 0 {
+					current
+				} else {
+					current.saturating_sub(1).saturating_sub(opacity)
+				};
+
+				self.relax(light, queue, update.kind, down, expected);
+			}
+
+			for direction in [dir::Up, dir::MinusX, dir::PlusX, dir::MinusZ, dir::PlusZ].iter() {
+				if let Some(neighbor) = update.position.offset(*direction) {
+					let opacity = self.opacity.level(quad.get(neighbor));
+					let expected = current.saturating_sub(1).saturating_sub(opacity);
+
+					self.relax(light, queue, update.kind, neighbor, expected);
+				}
+			}
+		}
+	}
+
+	fn relax(&self, light: &mut LightData, queue: &mut VecDeque<LightUpdate>, kind: LightKind, position: QuadPosition, expected: u8) {
+		if light.get(kind, position) < expected {
+			light.set(kind, position, expected);
+			queue.push_back(LightUpdate { kind, position });
+		}
+	}
+}
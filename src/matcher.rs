@@ -4,8 +4,9 @@
 //! A component-based solution, in comparison, would be much more configurable.
 
 use vocs::indexed::Target;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::{IntoIterator, FromIterator, Iterator};
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BlockMatcher<B> where B: Target {
@@ -69,4 +70,158 @@ impl<B> BlockMatcher<B> where B: Target {
 		// Contains, Blacklist => 1 ^ 1 => 0
 		self.blocks.contains(block) ^ self.blacklist
 	}
+}
+
+/// Common interface for anything that can decide whether a block matches, whether it's
+/// matching by identity (`BlockMatcher`) or by material property (`Matcher`).
+pub trait Matches<B> where B: Target {
+	fn matches(&self, block: &B) -> bool;
+}
+
+impl<B> Matches<B> for BlockMatcher<B> where B: Target {
+	fn matches(&self, block: &B) -> bool {
+		BlockMatcher::matches(self, block)
+	}
+}
+
+/// The properties of a block that decorators and world generation care about,
+/// independent of which specific block they happen to be.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+pub struct Material {
+	pub solid: bool,
+	pub replaceable: bool,
+	pub ground_cover: bool,
+	pub renderable: bool,
+	pub emission: u8
+}
+
+/// Maps each block to its `Material`. Blocks with no entry are treated as
+/// having the default (non-solid, non-replaceable, unlit) material.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Materials<B> where B: Target {
+	materials: HashMap<B, Material>
+}
+
+impl<B> Materials<B> where B: Target {
+	pub fn new() -> Self {
+		Materials { materials: HashMap::new() }
+	}
+
+	pub fn set(&mut self, block: B, material: Material) {
+		self.materials.insert(block, material);
+	}
+
+	pub fn get(&self, block: &B) -> Material {
+		self.materials.get(block).cloned().unwrap_or_default()
+	}
+}
+
+impl<B> Default for Materials<B> where B: Target {
+	fn default() -> Self {
+		Materials::new()
+	}
+}
+
+/// A named `Material` field a `MaterialMatcher` can test against. Unlike a raw predicate,
+/// this is plain data, so it can be loaded from the same config files a `BlockMatcher`
+/// loads from instead of only being constructible from Rust source.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum MaterialProperty {
+	Solid,
+	Replaceable,
+	GroundCover,
+	Renderable
+}
+
+impl MaterialProperty {
+	fn test(&self, material: &Material) -> bool {
+		match *self {
+			MaterialProperty::Solid => material.solid,
+			MaterialProperty::Replaceable => material.replaceable,
+			MaterialProperty::GroundCover => material.ground_cover,
+			MaterialProperty::Renderable => material.renderable
+		}
+	}
+}
+
+/// Matches blocks by a property of their `Material` rather than by identity,
+/// so configuration can target a whole family of blocks (`solid`, `ground_cover`, ...)
+/// instead of enumerating every block that happens to have it.
+///
+/// `materials` is the world's shared registry rather than per-matcher config, so it's
+/// left out of (de)serialization and attached by whoever builds the matcher from config.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MaterialMatcher<B> where B: Target {
+	#[serde(skip)]
+	materials: Rc<Materials<B>>,
+	property: MaterialProperty
+}
+
+impl<B> MaterialMatcher<B> where B: Target {
+	pub fn new(materials: Rc<Materials<B>>, property: MaterialProperty) -> Self {
+		MaterialMatcher { materials, property }
+	}
+
+	/// Attaches the world's `Materials` registry. Config-loading code must call this
+	/// after deserializing, since `materials` is skipped during (de)serialization and
+	/// otherwise stays the empty default registry forever.
+	pub fn set_materials(&mut self, materials: Rc<Materials<B>>) {
+		self.materials = materials;
+	}
+
+	pub fn matches(&self, block: &B) -> bool {
+		self.property.test(&self.materials.get(block))
+	}
+}
+
+/// Either an exact `BlockMatcher` or a property-based `MaterialMatcher`, behind the
+/// single `matches(&B) -> bool` interface callers already rely on.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum Matcher<B> where B: Target {
+	Blocks(BlockMatcher<B>),
+	Material(MaterialMatcher<B>)
+}
+
+impl<B> Matcher<B> where B: Target {
+	pub fn matches(&self, block: &B) -> bool {
+		match *self {
+			Matcher::Blocks(ref matcher) => matcher.matches(block),
+			Matcher::Material(ref matcher) => matcher.matches(block)
+		}
+	}
+}
+
+impl<B> Matches<B> for Matcher<B> where B: Target {
+	fn matches(&self, block: &B) -> bool {
+		Matcher::matches(self, block)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+	struct TestBlock(u8);
+
+	impl Target for TestBlock {}
+
+	#[test]
+	fn material_matcher_attach_after_deserialize() {
+		// `#[serde(skip)]` leaves `materials` as `Rc::new(Materials::default())` on
+		// deserialize, i.e. an empty registry that matches nothing until attached.
+		let mut matcher = MaterialMatcher {
+			materials: Rc::new(Materials::default()),
+			property: MaterialProperty::Solid
+		};
+
+		assert!(!matcher.matches(&TestBlock(1)));
+
+		let mut materials = Materials::new();
+		materials.set(TestBlock(1), Material { solid: true, ..Material::default() });
+		matcher.set_materials(Rc::new(materials));
+
+		assert!(matcher.matches(&TestBlock(1)));
+		assert!(!matcher.matches(&TestBlock(2)));
+	}
 }
\ No newline at end of file
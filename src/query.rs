@@ -0,0 +1,70 @@
+//! Querying a quad for positions matching a selector, so decorators don't have to
+//! guess at or re-walk the volume themselves.
+
+use vocs::indexed::Target;
+use vocs::position::QuadPosition;
+use vocs::view::QuadMut;
+use matcher::Matches;
+
+pub trait QuadQuery<B> where B: Target {
+	/// The topmost position in column `(x, z)` matching `matcher`, if any.
+	fn column_top<M: Matches<B>>(&self, x: u8, z: u8, matcher: &M) -> Option<QuadPosition>;
+
+	/// Every position in the quad matching `matcher`, scanning column by column.
+	fn select<'a, M: Matches<B>>(&'a self, matcher: &'a M) -> Select<'a, B, M>;
+}
+
+impl<B> QuadQuery<B> for QuadMut<B> where B: Target {
+	fn column_top<M: Matches<B>>(&self, x: u8, z: u8, matcher: &M) -> Option<QuadPosition> {
+		for y in (0..256).rev() {
+			let position = QuadPosition::new(x, y as u16, z);
+
+			if matcher.matches(self.get(position)) {
+				return Some(position);
+			}
+		}
+
+		None
+	}
+
+	fn select<'a, M: Matches<B>>(&'a self, matcher: &'a M) -> Select<'a, B, M> {
+		Select { quad: self, matcher, x: 0, z: 0, y: 0 }
+	}
+}
+
+/// Iterator over every position in a quad matching a selector, in column-major order.
+pub struct Select<'a, B, M> where B: Target + 'a, M: Matches<B> + 'a {
+	quad: &'a QuadMut<B>,
+	matcher: &'a M,
+	x: u16,
+	z: u16,
+	y: u16
+}
+
+impl<'a, B, M> Iterator for Select<'a, B, M> where B: Target, M: Matches<B> {
+	type Item = QuadPosition;
+
+	fn next(&mut self) -> Option<QuadPosition> {
+		while self.x < 16 {
+			while self.z < 16 {
+				while self.y < 256 {
+					let position = QuadPosition::new(self.x as u8, self.y, self.z as u8);
+
+					self.y += 1;
+
+					if self.matcher.matches(self.quad.get(position)) {
+						return Some(position);
+					}
+				}
+
+				self.y = 0;
+				self.z += 1;
+			}
+
+			self.z = 0;
+			self.x += 1;
+		}
+
+		None
+	}
+}
@@ -0,0 +1,47 @@
+//! Gravity settling: compacts unsupported gravity-affected blocks (sand, gravel) down
+//! onto whatever solid ground lies beneath them, after decoration and surface fill.
+
+use vocs::indexed::Target;
+use vocs::position::QuadPosition;
+use vocs::view::QuadMut;
+use matcher::BlockMatcher;
+
+pub struct Settler<B> where B: Target {
+	/// Blocks affected by gravity (sand, gravel, ...).
+	pub falling: BlockMatcher<B>,
+	/// Blocks a falling block can pass through to keep sinking (air, water, ...).
+	pub replace: BlockMatcher<B>,
+	/// The block left behind once a falling block has moved on.
+	pub air: B
+}
+
+impl<B> Settler<B> where B: Target {
+	pub fn settle(&self, quad: &mut QuadMut<B>) {
+		for x in 0..16 {
+			for z in 0..16 {
+				self.settle_column(quad, x, z);
+			}
+		}
+	}
+
+	fn settle_column(&self, quad: &mut QuadMut<B>, x: u8, z: u8) {
+		// Lowest slot a falling block can come to rest in.
+		let mut rest: u16 = 0;
+
+		for y in 0..256u16 {
+			let position = QuadPosition::new(x, y, z);
+			let block = quad.get(position).clone();
+
+			if self.falling.matches(&block) {
+				if rest != y {
+					quad.set_immediate(QuadPosition::new(x, rest, z), &block);
+					quad.set_immediate(position, &self.air);
+				}
+
+				rest += 1;
+			} else if !self.replace.matches(&block) {
+				rest = y + 1;
+			}
+		}
+	}
+}
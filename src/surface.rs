@@ -1,5 +1,8 @@
 use chunk::storage::Target;
+use vocs::position::QuadPosition;
+use vocs::view::QuadMut;
 use biome::Biome;
+use matcher::BlockMatcher;
 
 const SEA_COORD:  u32 = 63;
 const BEACH_LOW:  u32 = SEA_COORD - 3;
@@ -10,6 +13,36 @@ pub struct Surface<B> where B: Target {
 	pub fill: B
 }
 
+impl<B> Clone for Surface<B> where B: Target {
+	fn clone(&self) -> Self {
+		Surface { top: self.top.clone(), fill: self.fill.clone() }
+	}
+}
+
+/// The concrete blocks a `Stack` reaches for while building a column.
+pub struct SurfaceBlocks<B> where B: Target {
+	pub sand: B,
+	pub gravel: B,
+	pub stone: B
+}
+
+/// Controls where sea level and the beach band sit, so non-vanilla worlds can tune them.
+pub struct SurfaceSettings {
+	pub sea_coord:  u32,
+	pub beach_low:  u32,
+	pub beach_high: u32
+}
+
+impl Default for SurfaceSettings {
+	fn default() -> Self {
+		SurfaceSettings {
+			sea_coord:  SEA_COORD,
+			beach_low:  BEACH_LOW,
+			beach_high: BEACH_HIGH
+		}
+	}
+}
+
 enum Beach {
 	Sand,
 	Gravel,
@@ -17,39 +50,79 @@ enum Beach {
 }
 
 impl Beach {
-	fn surface<B>(&self, biome: &Biome) -> Surface<B> where B: Target {
-		/*match *self {
-			Beach::Sand   => Surface { top: Some(Block::Sand), fill: Block::Sand },
-			Beach::Gravel => Surface { top: None, fill: Block::Gravel },
+	/// Classifies a column using a beach/gravel noise sample, mirroring the way terrain
+	/// tiers are chosen from a height value: the sample is bucketed against fixed
+	/// thresholds rather than compared against a single cutoff.
+	fn from_noise(sample: f64) -> Self {
+		if sample > 1.0 {
+			Beach::Gravel
+		} else if sample < -1.0 {
+			Beach::Biome
+		} else {
+			Beach::Sand
+		}
+	}
+
+	fn surface<B>(&self, biome: &Biome, blocks: &SurfaceBlocks<B>) -> Surface<B> where B: Target {
+		match *self {
+			Beach::Sand   => Surface { top: Some(blocks.sand.clone()), fill: blocks.sand.clone() },
+			Beach::Gravel => Surface { top: None, fill: blocks.gravel.clone() },
 			Beach::Biome  => biome.surface()
-		}*/
-		unimplemented!()
+		}
 	}
 }
 
-struct Stack {
-	depth: i32,
-	beach: Beach,
-	biome: Biome
+pub struct Stack<B> where B: Target {
+	/// Depth of biome-specific top/fill to place before cutting straight to stone.
+	pub depth: i32,
+	pub biome: Biome,
+	pub blocks: SurfaceBlocks<B>,
+	pub settings: SurfaceSettings,
+	/// Blocks eligible to be overwritten by the surface pass (the raw terrain placeholder).
+	/// Anything else already in the quad (carved caves, bedrock, ...) is left untouched.
+	pub replace: BlockMatcher<B>
 }
 
-impl Stack {
-	fn surface<B>(&self, y: u32, last: &Surface<B>) -> Surface<B> where B: Target {
-		let mut surface = if self.depth <= 0 {
-			//Surface { top: None, fill: Block::Stone }
-			unimplemented!()
-		} else if y >= BEACH_LOW && y <= BEACH_HIGH {
-			self.beach.surface(&self.biome)
+impl<B> Stack<B> where B: Target {
+	fn surface(&self, y: u32, depth: i32, beach_noise: f64, last: &Surface<B>) -> Surface<B> {
+		let mut surface = if depth <= 0 {
+			Surface { top: None, fill: self.blocks.stone.clone() }
+		} else if y >= self.settings.beach_low && y <= self.settings.beach_high {
+			Beach::from_noise(beach_noise).surface(&self.biome, &self.blocks)
 		} else {
-			//Surface { top: self.biome.surface().top, fill: last.fill }
-			unimplemented!()
+			// Only the first layer at the heightmap gets the biome's top block (e.g.
+			// grass); every layer beneath it falls through to `last.fill` (e.g. dirt).
+			let top = if depth == self.depth { self.biome.surface().top } else { None };
+
+			Surface { top, fill: last.fill.clone() }
 		};
-		
-		if y < SEA_COORD {
-			unimplemented!()
-			//surface.top = Some(surface.fill);
+
+		if y < self.settings.sea_coord {
+			surface.top = Some(surface.fill.clone());
 		}
-		
+
 		surface
 	}
-}
\ No newline at end of file
+
+	/// Walks a single column downward from the heightmap, replacing `self.depth` blocks
+	/// with biome-specific top/fill and writing the result into the quad.
+	pub fn build_column(&self, quad: &mut QuadMut<B>, x: u8, z: u8, height: u32, beach_noise: f64) {
+		let top = self.biome.surface();
+		let mut last = Surface { top: top.top.clone(), fill: top.fill.clone() };
+
+		for y in (0..=height).rev() {
+			let depth = self.depth - (height - y) as i32;
+			let surface = self.surface(y, depth, beach_noise, &last);
+			let position = QuadPosition::new(x, y as u16, z);
+
+			// Never overwrite anything but the raw terrain placeholder, so carved caves
+			// and bedrock placed before the surface pass survive it.
+			if self.replace.matches(quad.get(position)) {
+				let block = surface.top.clone().unwrap_or_else(|| surface.fill.clone());
+				quad.set_immediate(position, &block);
+			}
+
+			last = surface;
+		}
+	}
+}